@@ -4,7 +4,9 @@ use ink_lang as ink;
 
 #[ink::contract]
 mod elecrypto {
+    use ink_prelude::string::String;
     use ink_storage::traits::SpreadAllocate;
+    use scale::Encode;
 
     #[ink(event)]
     pub struct Transfer {
@@ -26,6 +28,62 @@ mod elecrypto {
         value: Balance,
     }
 
+    #[ink(event)]
+    pub struct OfferFilled {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        buyer: AccountId,
+        energy_amount: Balance,
+        price: Balance,
+    }
+
+    /// An energy-trade offer, filled out of the allowance the seller grants to
+    /// this contract. Listing does not check that the allowance exists.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Order {
+        seller: AccountId,
+        energy_amount: Balance,
+        price: Balance,
+    }
+
+    /// The Elecrypto error types.
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// Returned if not enough balance to fulfill a request is available.
+        InsufficientBalance,
+        /// Returned if not enough allowance to fulfill a request is available.
+        InsufficientAllowance,
+        /// Returned if a caller other than the grid operator attempts to mint.
+        NotOwner,
+        /// Returned if a caller attempts to burn tokens from an account other
+        /// than their own.
+        NotTokenOwner,
+        /// Returned if minting would overflow the total supply.
+        TotalSupplyOverflow,
+        /// Returned if increasing an allowance would overflow its value.
+        AllowanceOverflow,
+        /// Returned if a cross-chain receipt's signature does not recover to the
+        /// bridge's public key.
+        BadSignature,
+        /// Returned if a cross-chain receipt's nonce has already been minted.
+        ReceiptAlreadyUsed,
+        /// Returned if an energy-trade offer with the given id does not exist.
+        OrderNotFound,
+        /// Returned if a caller other than an offer's seller attempts to cancel it.
+        NotSeller,
+        /// Returned if `fill_offer` is not paid exactly the listed price, or the
+        /// resulting payout to the seller fails.
+        IncorrectPayment,
+    }
+
+    /// The Elecrypto result type.
+    pub type Result<T> = core::result::Result<T, Error>;
+
     #[cfg(not(feature = "ink-as-dependency"))]
     #[ink(storage)]
     #[derive(SpreadAllocate)]
@@ -36,15 +94,63 @@ mod elecrypto {
         balances: ink_storage::Mapping<AccountId, Balance>,
         /// Approval spender on behalf of the message's sender.
         allowances: ink_storage::Mapping<(AccountId, AccountId), Balance>,
+        /// The grid operator account authorized to mint new energy tokens.
+        owner: AccountId,
+        /// The bridge's compressed secp256k1 public key, used to verify
+        /// cross-chain mint receipts.
+        bridge_pubkey: [u8; 33],
+        /// Receipt nonces that have already been minted, to prevent replay.
+        consumed_nonces: ink_storage::Mapping<u128, ()>,
+        /// Open energy-trade offers, keyed by offer id.
+        orders: ink_storage::Mapping<u32, Order>,
+        /// The id to assign to the next listed offer.
+        next_order_id: u32,
+        /// The token's display name, e.g. "Elecrypto kWh".
+        name: String,
+        /// The token's display symbol, e.g. "EKWH".
+        symbol: String,
+        /// The number of decimals used to denominate the token (e.g. 3 for Wh
+        /// precision on a kWh-denominated token).
+        decimals: u8,
     }
 
     impl Elecrypto {
         #[ink(constructor)]
-        pub fn new(initial_supply: Balance) -> Self {
+        pub fn new(initial_supply: Balance, bridge_pubkey: [u8; 33]) -> Self {
+            ink_lang::utils::initialize_contract(|contract: &mut Self| {
+                contract.total_supply = initial_supply;
+                let caller = Self::env().caller();
+                contract.balances.insert(&caller, &initial_supply);
+                contract.owner = caller;
+                contract.bridge_pubkey = bridge_pubkey;
+
+                Self::env().emit_event(Transfer {
+                    from: None,
+                    to: Some(caller),
+                    value: initial_supply,
+                });
+            })
+        }
+
+        /// Like [`Self::new`], but also labels the token for front-ends, mirroring
+        /// the pop-api fungibles design.
+        #[ink(constructor, payable)]
+        pub fn new_with_metadata(
+            initial_supply: Balance,
+            bridge_pubkey: [u8; 33],
+            name: String,
+            symbol: String,
+            decimals: u8,
+        ) -> Self {
             ink_lang::utils::initialize_contract(|contract: &mut Self| {
                 contract.total_supply = initial_supply;
                 let caller = Self::env().caller();
                 contract.balances.insert(&caller, &initial_supply);
+                contract.owner = caller;
+                contract.bridge_pubkey = bridge_pubkey;
+                contract.name = name;
+                contract.symbol = symbol;
+                contract.decimals = decimals;
 
                 Self::env().emit_event(Transfer {
                     from: None,
@@ -53,6 +159,7 @@ mod elecrypto {
                 });
             })
         }
+
         // Return function of total supply
         #[ink(message)]
         pub fn total_supply(&self) -> Balance {
@@ -65,6 +172,21 @@ mod elecrypto {
             self.balances.get(&owner).unwrap_or_default()
         }
 
+        #[ink(message)]
+        pub fn token_name(&self) -> String {
+            self.name.clone()
+        }
+
+        #[ink(message)]
+        pub fn token_symbol(&self) -> String {
+            self.symbol.clone()
+        }
+
+        #[ink(message)]
+        pub fn token_decimals(&self) -> u8 {
+            self.decimals
+        }
+
         #[ink(message)]
         pub fn approve(&mut self, spender: AccountId, value: Balance) -> bool {
             // Record the new allowance.
@@ -86,34 +208,261 @@ mod elecrypto {
             self.allowance_of_or_zero(&owner, &spender)
         }
 
+        /// Increases the allowance granted to `spender` by `delta`, avoiding the
+        /// classic approve-overwrite race where a spender could front-run an
+        /// allowance change and spend both the old and new amounts.
         #[ink(message)]
-        pub fn transfer_from(&mut self, from: AccountId, to: AccountId, value: Balance) -> bool {
-            // Ensure that a sufficient allowance exists.
+        pub fn increase_allowance(&mut self, spender: AccountId, delta: Balance) -> Result<()> {
+            let owner = self.env().caller();
+            let allowance = self.allowance_of_or_zero(&owner, &spender);
+            let value = allowance
+                .checked_add(delta)
+                .ok_or(Error::AllowanceOverflow)?;
+            self.allowances.insert(&(owner, spender), &value);
+
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value,
+            });
+
+            Ok(())
+        }
+
+        /// Decreases the allowance granted to `spender` by `delta`, rejecting
+        /// the call instead of saturating to zero when `delta` exceeds the
+        /// current allowance.
+        #[ink(message)]
+        pub fn decrease_allowance(&mut self, spender: AccountId, delta: Balance) -> Result<()> {
+            let owner = self.env().caller();
+            let allowance = self.allowance_of_or_zero(&owner, &spender);
+            let value = allowance
+                .checked_sub(delta)
+                .ok_or(Error::InsufficientAllowance)?;
+            self.allowances.insert(&(owner, spender), &value);
+
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<()> {
             let caller = self.env().caller();
-            let allowance = self.allowance_of_or_zero(&from, &caller);
-            if allowance < value {
-                return false;
+            self.spend_allowance_and_transfer(from, caller, to, value)
+        }
+
+        #[ink(message)]
+        pub fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            self.transfer_from_to(self.env().caller(), to, value)
+        }
+
+        /// Mints new energy tokens to `to`, representing kWh fed into the grid.
+        ///
+        /// Only the grid operator account set at construction may call this.
+        #[ink(message)]
+        pub fn mint(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+
+            self.total_supply = self
+                .total_supply
+                .checked_add(value)
+                .ok_or(Error::TotalSupplyOverflow)?;
+            let to_balance = self.balance_of(to);
+            self.balances.insert(&to, &(to_balance + value));
+
+            self.env().emit_event(Transfer {
+                from: None,
+                to: Some(to),
+                value,
+            });
+
+            Ok(())
+        }
+
+        /// Burns energy tokens from `from`, representing kWh drawn from the grid.
+        ///
+        /// A caller may only burn their own tokens.
+        #[ink(message)]
+        pub fn burn(&mut self, from: AccountId, value: Balance) -> Result<()> {
+            if self.env().caller() != from {
+                return Err(Error::NotTokenOwner);
             }
 
-            let transfer_result = self.transfer_from_to(from, to, value);
-            if !transfer_result {
-                return false;
+            let from_balance = self.balance_of(from);
+            if from_balance < value {
+                return Err(Error::InsufficientBalance);
             }
 
-            // Deduct the value of the allowance token and transfer the tokens.
-            self.allowances.insert((from, caller), &(allowance - value));
-            true
+            self.balances.insert(&from, &(from_balance - value));
+            self.total_supply -= value;
+
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: None,
+                value,
+            });
+
+            Ok(())
         }
 
+        /// Mints tokens to `recipient` on the strength of a bridge-signed receipt
+        /// `(recipient, amount, nonce)`, letting energy credits bridge in from
+        /// another chain.
+        ///
+        /// The nonce is recorded as consumed *before* the balance is credited,
+        /// so the same receipt can never be replayed to double-mint.
         #[ink(message)]
-        pub fn transfer(&mut self, to: AccountId, value: Balance) -> bool {
-            self.transfer_from_to(self.env().caller(), to, value)
+        pub fn mint_with_receipt(
+            &mut self,
+            recipient: AccountId,
+            amount: Balance,
+            nonce: u128,
+            signature: [u8; 65],
+        ) -> Result<()> {
+            if self.consumed_nonces.contains(&nonce) {
+                return Err(Error::ReceiptAlreadyUsed);
+            }
+
+            let encoded_receipt = (recipient, amount, nonce).encode();
+            let mut message_hash = <ink_env::hash::Keccak256 as ink_env::hash::HashOutput>::Type::default();
+            self.env()
+                .hash_bytes::<ink_env::hash::Keccak256>(&encoded_receipt, &mut message_hash);
+
+            let mut recovered_pubkey = [0u8; 33];
+            ink_env::ecdsa_recover(&signature, &message_hash, &mut recovered_pubkey)
+                .map_err(|_| Error::BadSignature)?;
+            if recovered_pubkey != self.bridge_pubkey {
+                return Err(Error::BadSignature);
+            }
+
+            self.consumed_nonces.insert(&nonce, &());
+
+            self.total_supply = self
+                .total_supply
+                .checked_add(amount)
+                .ok_or(Error::TotalSupplyOverflow)?;
+            let recipient_balance = self.balance_of(recipient);
+            let new_balance = recipient_balance
+                .checked_add(amount)
+                .ok_or(Error::TotalSupplyOverflow)?;
+            self.balances.insert(&recipient, &new_balance);
+
+            self.env().emit_event(Transfer {
+                from: None,
+                to: Some(recipient),
+                value: amount,
+            });
+
+            Ok(())
         }
 
-        fn transfer_from_to(&mut self, from: AccountId, to: AccountId, value: Balance) -> bool {
+        /// Lists an offer to sell `energy_amount` of energy tokens at `price`,
+        /// denominated in the chain's native currency and collected from the
+        /// buyer when the offer is filled.
+        ///
+        /// The seller keeps custody of their tokens; they must have already
+        /// `approve`d this contract to move `energy_amount` on their behalf so
+        /// that `fill_offer` can later pull the tokens out of that allowance,
+        /// as `transfer_from` does for its caller. This call does not check
+        /// the allowance.
+        #[ink(message)]
+        pub fn list_offer(&mut self, energy_amount: Balance, price: Balance) -> u32 {
+            let seller = self.env().caller();
+            let id = self.next_order_id;
+            self.orders.insert(
+                &id,
+                &Order {
+                    seller,
+                    energy_amount,
+                    price,
+                },
+            );
+            self.next_order_id += 1;
+            id
+        }
+
+        /// Fills offer `id`, paying `price` to the seller and pulling the
+        /// seller's energy tokens to the caller out of the allowance the
+        /// seller granted to this contract when listing. The call must
+        /// transfer exactly `price`, or it fails with
+        /// `Error::IncorrectPayment`. Fails with `Error::InsufficientAllowance`
+        /// if the seller revoked that approval since listing.
+        #[ink(message, payable)]
+        pub fn fill_offer(&mut self, id: u32) -> Result<()> {
+            let order = self.orders.get(&id).ok_or(Error::OrderNotFound)?;
+            if self.env().transferred_value() != order.price {
+                return Err(Error::IncorrectPayment);
+            }
+
+            let buyer = self.env().caller();
+            let contract_account = self.env().account_id();
+
+            self.spend_allowance_and_transfer(order.seller, contract_account, buyer, order.energy_amount)?;
+            self.orders.remove(&id);
+
+            self.env()
+                .transfer(order.seller, order.price)
+                .map_err(|_| Error::IncorrectPayment)?;
+
+            self.env().emit_event(OfferFilled {
+                id,
+                seller: order.seller,
+                buyer,
+                energy_amount: order.energy_amount,
+                price: order.price,
+            });
+
+            Ok(())
+        }
+
+        /// Cancels offer `id`. Only the seller who listed it may cancel.
+        #[ink(message)]
+        pub fn cancel_offer(&mut self, id: u32) -> Result<()> {
+            let order = self.orders.get(&id).ok_or(Error::OrderNotFound)?;
+            if self.env().caller() != order.seller {
+                return Err(Error::NotSeller);
+            }
+
+            self.orders.remove(&id);
+            Ok(())
+        }
+
+        /// Moves `value` from `from` to `to` out of the allowance `from` has
+        /// granted to `spender`, deducting that allowance on success.
+        fn spend_allowance_and_transfer(
+            &mut self,
+            from: AccountId,
+            spender: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<()> {
+            let allowance = self.allowance_of_or_zero(&from, &spender);
+            if allowance < value {
+                return Err(Error::InsufficientAllowance);
+            }
+
+            self.transfer_from_to(from, to, value)?;
+
+            self.allowances.insert((from, spender), &(allowance - value));
+            Ok(())
+        }
+
+        fn transfer_from_to(&mut self, from: AccountId, to: AccountId, value: Balance) -> Result<()> {
             let from_balance = self.balance_of(from);
             if from_balance < value {
-                return false;
+                return Err(Error::InsufficientBalance);
             }
 
             // Update the sender's balance.
@@ -129,7 +478,7 @@ mod elecrypto {
                 value,
             });
 
-            true
+            Ok(())
         }
 
         fn allowance_of_or_zero(&self, owner: &AccountId, spender: &AccountId) -> Balance {
@@ -147,13 +496,13 @@ mod elecrypto {
 
         #[ink::test]
         fn new_works() {
-            let contract = Elecrypto::new(7000000);
+            let contract = Elecrypto::new(7000000, [0u8; 33]);
             assert_eq!(contract.total_supply(), 7000000);
         }
 
         #[ink::test]
         fn balance_works() {
-            let contract = Elecrypto::new(23000);
+            let contract = Elecrypto::new(23000, [0u8; 33]);
             assert_eq!(contract.total_supply(), 23000);
             assert_eq!(contract.balance_of(AccountId::from([0x1; 32])), 23000);
             assert_eq!(contract.balance_of(AccountId::from([0x0; 32])), 0);
@@ -161,16 +510,19 @@ mod elecrypto {
 
         #[ink::test]
         fn transfer_works() {
-            let mut contract = Elecrypto::new(100);
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
             assert_eq!(contract.balance_of(AccountId::from([0x1; 32])), 100);
-            assert!(contract.transfer(AccountId::from([0x0; 32]), 10));
+            assert_eq!(contract.transfer(AccountId::from([0x0; 32]), 10), Ok(()));
             assert_eq!(contract.balance_of(AccountId::from([0x0; 32])), 10);
-            assert!(!contract.transfer(AccountId::from([0x0; 32]), 100));
+            assert_eq!(
+                contract.transfer(AccountId::from([0x0; 32]), 100),
+                Err(Error::InsufficientBalance)
+            );
         }
 
         #[ink::test]
         fn transfer_from_works() {
-            let mut contract = Elecrypto::new(100);
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
             assert_eq!(contract.balance_of(AccountId::from([0x1; 32])), 100);
             contract.approve(AccountId::from([0x1; 32]), 20);
             contract.transfer_from(AccountId::from([0x1; 32]), AccountId::from([0x0; 32]), 10);
@@ -179,7 +531,7 @@ mod elecrypto {
 
         #[ink::test]
         fn allowances_works() {
-            let mut contract = Elecrypto::new(100);
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
             assert_eq!(contract.balance_of(AccountId::from([0x1; 32])), 100);
             contract.approve(AccountId::from([0x1; 32]), 200);
             assert_eq!(
@@ -187,27 +539,209 @@ mod elecrypto {
                 200
             );
 
-            assert!(contract.transfer_from(
-                AccountId::from([0x1; 32]),
-                AccountId::from([0x0; 32]),
-                50
-            ));
+            assert_eq!(
+                contract.transfer_from(
+                    AccountId::from([0x1; 32]),
+                    AccountId::from([0x0; 32]),
+                    50
+                ),
+                Ok(())
+            );
             assert_eq!(contract.balance_of(AccountId::from([0x0; 32])), 50);
             assert_eq!(
                 contract.allowancetoken(AccountId::from([0x1; 32]), AccountId::from([0x1; 32])),
                 150
             );
 
-            assert!(!contract.transfer_from(
-                AccountId::from([0x1; 32]),
-                AccountId::from([0x0; 32]),
-                100
-            ));
+            // Allowance is still sufficient (150), but the sender's balance (50) is not.
+            assert_eq!(
+                contract.transfer_from(
+                    AccountId::from([0x1; 32]),
+                    AccountId::from([0x0; 32]),
+                    100
+                ),
+                Err(Error::InsufficientBalance)
+            );
             assert_eq!(contract.balance_of(AccountId::from([0x0; 32])), 50);
             assert_eq!(
                 contract.allowancetoken(AccountId::from([0x1; 32]), AccountId::from([0x1; 32])),
                 150
             );
+
+            // Now the allowance itself is the limiting factor.
+            assert_eq!(
+                contract.transfer_from(
+                    AccountId::from([0x1; 32]),
+                    AccountId::from([0x0; 32]),
+                    151
+                ),
+                Err(Error::InsufficientAllowance)
+            );
+        }
+
+        #[ink::test]
+        fn mint_works() {
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
+            let to = AccountId::from([0x0; 32]);
+            assert_eq!(contract.mint(to, 50), Ok(()));
+            assert_eq!(contract.balance_of(to), 50);
+            assert_eq!(contract.total_supply(), 150);
+        }
+
+        #[ink::test]
+        fn mint_fails_for_non_owner() {
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+            assert_eq!(
+                contract.mint(AccountId::from([0x0; 32]), 50),
+                Err(Error::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn mint_fails_on_total_supply_overflow() {
+            let mut contract = Elecrypto::new(Balance::MAX, [0u8; 33]);
+            assert_eq!(
+                contract.mint(AccountId::from([0x0; 32]), 1),
+                Err(Error::TotalSupplyOverflow)
+            );
+        }
+
+        #[ink::test]
+        fn burn_works() {
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
+            let from = AccountId::from([0x1; 32]);
+            assert_eq!(contract.burn(from, 40), Ok(()));
+            assert_eq!(contract.balance_of(from), 60);
+            assert_eq!(contract.total_supply(), 60);
+
+            assert_eq!(contract.burn(from, 1000), Err(Error::InsufficientBalance));
+        }
+
+        #[ink::test]
+        fn burn_fails_for_other_accounts_tokens() {
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
+            let from = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+            assert_eq!(contract.burn(from, 40), Err(Error::NotTokenOwner));
+        }
+
+        #[ink::test]
+        fn increase_allowance_works() {
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
+            let spender = AccountId::from([0x1; 32]);
+            contract.approve(spender, 100);
+            assert_eq!(contract.increase_allowance(spender, 50), Ok(()));
+            assert_eq!(
+                contract.allowancetoken(AccountId::from([0x1; 32]), spender),
+                150
+            );
+        }
+
+        #[ink::test]
+        fn increase_allowance_fails_on_overflow() {
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
+            let spender = AccountId::from([0x1; 32]);
+            contract.approve(spender, Balance::MAX);
+            assert_eq!(
+                contract.increase_allowance(spender, 1),
+                Err(Error::AllowanceOverflow)
+            );
+        }
+
+        #[ink::test]
+        fn decrease_allowance_works() {
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
+            let spender = AccountId::from([0x1; 32]);
+            contract.approve(spender, 100);
+            assert_eq!(contract.decrease_allowance(spender, 40), Ok(()));
+            assert_eq!(
+                contract.allowancetoken(AccountId::from([0x1; 32]), spender),
+                60
+            );
+
+            assert_eq!(
+                contract.decrease_allowance(spender, 1000),
+                Err(Error::InsufficientAllowance)
+            );
+        }
+
+        #[ink::test]
+        fn mint_with_receipt_rejects_bad_signature() {
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
+            assert_eq!(
+                contract.mint_with_receipt(AccountId::from([0x0; 32]), 50, 1, [0u8; 65]),
+                Err(Error::BadSignature)
+            );
+        }
+
+        #[ink::test]
+        fn fill_offer_works() {
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
+            let seller = AccountId::from([0x1; 32]);
+            let buyer = AccountId::from([0x2; 32]);
+            let contract_account = ink_env::test::callee::<ink_env::DefaultEnvironment>();
+            contract.approve(contract_account, 30);
+            let id = contract.list_offer(30, 300);
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(buyer);
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(300);
+            assert_eq!(contract.fill_offer(id), Ok(()));
+            assert_eq!(contract.balance_of(seller), 70);
+            assert_eq!(contract.balance_of(buyer), 30);
+            assert_eq!(contract.fill_offer(id), Err(Error::OrderNotFound));
+        }
+
+        #[ink::test]
+        fn fill_offer_fails_on_wrong_payment() {
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
+            let contract_account = ink_env::test::callee::<ink_env::DefaultEnvironment>();
+            contract.approve(contract_account, 30);
+            let id = contract.list_offer(30, 300);
+
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(299);
+            assert_eq!(contract.fill_offer(id), Err(Error::IncorrectPayment));
+        }
+
+        #[ink::test]
+        fn fill_offer_fails_without_allowance() {
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
+            let id = contract.list_offer(30, 300);
+
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(300);
+            assert_eq!(contract.fill_offer(id), Err(Error::InsufficientAllowance));
+        }
+
+        #[ink::test]
+        fn cancel_offer_works() {
+            let mut contract = Elecrypto::new(100, [0u8; 33]);
+            let id = contract.list_offer(30, 300);
+
+            assert_eq!(contract.cancel_offer(id), Ok(()));
+            assert_eq!(contract.fill_offer(id), Err(Error::OrderNotFound));
+        }
+
+        #[ink::test]
+        fn new_defaults_metadata() {
+            let contract = Elecrypto::new(100, [0u8; 33]);
+            assert_eq!(contract.token_name(), String::from(""));
+            assert_eq!(contract.token_symbol(), String::from(""));
+            assert_eq!(contract.token_decimals(), 0);
+        }
+
+        #[ink::test]
+        fn new_with_metadata_works() {
+            let contract = Elecrypto::new_with_metadata(
+                100,
+                [0u8; 33],
+                String::from("Elecrypto kWh"),
+                String::from("EKWH"),
+                3,
+            );
+            assert_eq!(contract.total_supply(), 100);
+            assert_eq!(contract.token_name(), String::from("Elecrypto kWh"));
+            assert_eq!(contract.token_symbol(), String::from("EKWH"));
+            assert_eq!(contract.token_decimals(), 3);
         }
     }
 }